@@ -42,12 +42,28 @@
 //! # })
 //! ```
 //!
-//! # Limitations
-//! - __Leading debounce.__ This library currently only implements trailing
-//!   debounce. It does not implement leading debounce.
+//! ## Leading and Trailing Edges
+//! By default, [`debounced`] only yields the most recent item of a burst once
+//! it has gone quiet (trailing debounce). If you'd rather emit the first item
+//! of a burst right away instead of the last one (leading debounce), or emit
+//! both the first and the last item, use [`debounced_with`] with a
+//! [`DebounceEdge`].
+//!
+//! ```rust
+//! # use std::time::Duration;
+//! # use futures_util::{SinkExt, StreamExt};
+//! # tokio_test::block_on(async {
+//! use debounced_wasm::{debounced_with, DebounceEdge};
+//!
+//! let (mut sender, receiver) = futures_channel::mpsc::channel(1024);
+//! let mut debounced = debounced_with(receiver, Duration::from_secs(1), DebounceEdge::Leading);
+//! sender.send(21).await;
+//! assert_eq!(debounced.next().await, Some(21));
+//! # })
+//! ```
 
 mod future;
 mod stream;
 
 pub use future::{delayed, Delayed};
-pub use stream::{debounced, Debounced};
+pub use stream::{debounced, debounced_with, DebounceEdge, Debounced};
@@ -25,13 +25,36 @@ use super::{delayed, Delayed};
 /// std::mem::drop(sender);
 /// assert_eq!(debounced.next().await, None);
 /// # })
+/// ```
+///
+/// The above is the trailing behavior of [`Debounced::new`]. A stream built
+/// with [`Debounced::new_with`] yields the items of a burst that its
+/// [`DebounceEdge`] selects.
 pub struct Debounced<S>
 where
     S: Stream,
 {
     stream: S,
     delay: Duration,
-    pending: Option<Delayed<S::Item>>,
+    edge: DebounceEdge,
+    window: Option<Delayed<Option<S::Item>>>,
+}
+
+/// Selects which items of a debounced burst are emitted.
+///
+/// A "burst" is a run of items that arrive less than `delay` apart from one
+/// another; the burst ends once `delay` has passed without a new item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebounceEdge {
+    /// Emit only the first item of a burst, immediately. Every other item in
+    /// the burst is suppressed.
+    Leading,
+    /// Emit only the last item of a burst, once `delay` has passed without a
+    /// new item. This is the default behavior.
+    Trailing,
+    /// Emit the first item of a burst immediately, and also emit the last
+    /// item of the burst once `delay` has passed without a new item.
+    Both,
 }
 
 impl<S> Debounced<S>
@@ -41,10 +64,17 @@ where
     /// Returns a new stream that delays its items for a given duration and only
     /// yields the most recent item afterwards.
     pub fn new(stream: S, delay: Duration) -> Debounced<S> {
+        Debounced::new_with(stream, delay, DebounceEdge::Trailing)
+    }
+
+    /// Returns a new stream that debounces its items for a given duration,
+    /// using the given `edge` to decide which items of a burst are emitted.
+    pub fn new_with(stream: S, delay: Duration, edge: DebounceEdge) -> Debounced<S> {
         Debounced {
             stream,
             delay,
-            pending: None,
+            edge,
+            window: None,
         }
     }
 }
@@ -56,27 +86,48 @@ where
     type Item = S::Item;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        while let Poll::Ready(next) = self.stream.poll_next_unpin(cx) {
-            match next {
-                Some(next) => self.pending = Some(delayed(next, self.delay)),
-                None => {
-                    if self.pending.is_none() {
-                        return Poll::Ready(None);
+        loop {
+            while let Poll::Ready(next) = self.stream.poll_next_unpin(cx) {
+                match next {
+                    Some(next) => {
+                        let idle = self.window.is_none();
+
+                        match (self.edge, idle) {
+                            (DebounceEdge::Leading, true) | (DebounceEdge::Both, true) => {
+                                self.window = Some(delayed(None, self.delay));
+                                return Poll::Ready(Some(next));
+                            }
+                            (DebounceEdge::Leading, false) => {
+                                self.window = Some(delayed(None, self.delay));
+                            }
+                            (DebounceEdge::Trailing, _) | (DebounceEdge::Both, false) => {
+                                self.window = Some(delayed(Some(next), self.delay));
+                            }
+                        }
+                    }
+                    None => {
+                        if self.window.is_none() {
+                            return Poll::Ready(None);
+                        }
+                        break;
                     }
-                    break;
                 }
             }
-        }
 
-        match self.pending.as_mut() {
-            Some(pending) => match pending.poll_unpin(cx) {
-                Poll::Ready(value) => {
-                    let _ = self.pending.take();
-                    Poll::Ready(Some(value))
-                }
-                Poll::Pending => Poll::Pending,
-            },
-            None => Poll::Pending,
+            match self.window.as_mut() {
+                Some(window) => match window.poll_unpin(cx) {
+                    Poll::Ready(value) => {
+                        let _ = self.window.take();
+
+                        match value {
+                            Some(value) => return Poll::Ready(Some(value)),
+                            None => continue,
+                        }
+                    }
+                    Poll::Pending => return Poll::Pending,
+                },
+                None => return Poll::Pending,
+            }
         }
     }
 }
@@ -107,6 +158,28 @@ where
     Debounced::new(stream, delay)
 }
 
+/// Returns a new stream that debounces its items for a given duration, using
+/// the given `edge` to decide which items of a burst are emitted.
+///
+/// ```rust
+/// # use std::time::{Duration, Instant};
+/// # use futures_util::{SinkExt, StreamExt};
+/// # tokio_test::block_on(async {
+/// use debounced::{debounced_with, DebounceEdge};
+///
+/// # let start = Instant::now();
+/// let (mut sender, receiver) = futures_channel::mpsc::channel(1024);
+/// let mut debounced = debounced_with(receiver, Duration::from_secs(1), DebounceEdge::Leading);
+/// sender.send(21).await;
+/// assert_eq!(debounced.next().await, Some(21));
+/// # })
+pub fn debounced_with<S>(stream: S, delay: Duration, edge: DebounceEdge) -> Debounced<S>
+where
+    S: Stream + Unpin,
+{
+    Debounced::new_with(stream, delay, edge)
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::{Arc, Mutex};
@@ -117,7 +190,7 @@ mod tests {
     use futures_util::{SinkExt, StreamExt};
     use tokio::time::sleep;
 
-    use super::debounced;
+    use super::{debounced, debounced_with, DebounceEdge};
 
     #[tokio::test]
     async fn test_debounce() {
@@ -185,4 +258,32 @@ mod tests {
             ]
         );
     }
+
+    #[tokio::test]
+    async fn test_debounce_leading() {
+        let start = Instant::now();
+        let (mut sender, receiver) = futures_channel::mpsc::channel(1024);
+        let mut debounced = debounced_with(receiver, Duration::from_secs(1), DebounceEdge::Leading);
+        let _ = sender.send(21).await;
+        let _ = sender.send(42).await;
+        assert_eq!(debounced.next().await, Some(21));
+        assert!(start.elapsed().as_secs() < 1);
+        std::mem::drop(sender);
+        assert_eq!(debounced.next().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_debounce_both() {
+        let start = Instant::now();
+        let (mut sender, receiver) = futures_channel::mpsc::channel(1024);
+        let mut debounced = debounced_with(receiver, Duration::from_secs(1), DebounceEdge::Both);
+        let _ = sender.send(21).await;
+        let _ = sender.send(42).await;
+        assert_eq!(debounced.next().await, Some(21));
+        assert!(start.elapsed().as_secs() < 1);
+        assert_eq!(debounced.next().await, Some(42));
+        assert_eq!(start.elapsed().as_secs(), 1);
+        std::mem::drop(sender);
+        assert_eq!(debounced.next().await, None);
+    }
 }